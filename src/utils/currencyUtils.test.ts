@@ -29,6 +29,11 @@ describe('currencyUtils', () => {
         expect(result).toEqual({ value: 1234.56, currency: 'INR' });
       });
 
+      it('should handle INR with a non-breaking space after the symbol', () => {
+        const result = parseCurrency('₹ 1,234.56');
+        expect(result).toEqual({ value: 1234.56, currency: 'INR' });
+      });
+
       it('should handle INR with leading/trailing whitespace', () => {
         const result = parseCurrency('  ₹1,234.56  ');
         expect(result).toEqual({ value: 1234.56, currency: 'INR' });